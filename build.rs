@@ -11,6 +11,9 @@ struct CountryCode {
     name: String,
     dial_code: String,
     code: String,
+    /// Valid national-number lengths (digit counts) for this country, when known.
+    #[serde(default)]
+    national_number_lengths: Vec<u8>,
 }
 
 fn main() {
@@ -37,8 +40,8 @@ fn main() {
         seen_codes.insert(dial_code.to_string());
 
         let country_info = format!(
-            r#"CountryInfo {{ name: "{}", iso_code: "{}" }}"#,
-            country.name, country.code
+            r#"CountryInfo {{ name: "{}", iso_code: "{}", valid_lengths: &{:?} }}"#,
+            country.name, country.code, country.national_number_lengths
         );
         dial_code_map.entry(dial_code, &country_info);
     }
@@ -51,6 +54,8 @@ fn main() {
 pub struct CountryInfo {{
     pub name: &'static str,
     pub iso_code: &'static str,
+    /// Valid national-number lengths for this country. Empty when unknown.
+    pub valid_lengths: &'static [u8],
 }}
 
 pub static COUNTRY_CODES: phf::Map<&'static str, CountryInfo> = {};
@@ -59,6 +64,36 @@ pub static COUNTRY_CODES: phf::Map<&'static str, CountryInfo> = {};
     )
     .unwrap();
 
+    // Create a reverse index from ISO 3166-1 alpha-2 code to dial code, so a
+    // default region can be used to parse bare national numbers.
+    let mut iso_to_dial_code_map = Map::new();
+    let mut seen_iso_codes = std::collections::HashSet::new();
+
+    for country in &country_codes {
+        if seen_iso_codes.contains(&country.code) {
+            continue;
+        }
+        seen_iso_codes.insert(country.code.clone());
+
+        let dial_code = country.dial_code.trim_start_matches('+');
+        iso_to_dial_code_map.entry(country.code.as_str(), &format!("{:?}", dial_code));
+    }
+
+    writeln!(
+        &mut file,
+        r#"
+/// Maps an ISO 3166-1 alpha-2 region code to its dial code.
+pub static ISO_TO_DIAL_CODE: phf::Map<&'static str, &'static str> = {};
+
+/// Find the dial code registered for a given ISO 3166-1 alpha-2 region code.
+pub fn find_dial_code_for_region(iso_code: &str) -> Option<&'static str> {{
+    ISO_TO_DIAL_CODE.get(iso_code).copied()
+}}
+"#,
+        iso_to_dial_code_map.build()
+    )
+    .unwrap();
+
     // Create a sorted list of country codes by length for efficient parsing
     let mut codes_by_length: Vec<String> = country_codes
         .iter()
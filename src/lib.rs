@@ -8,6 +8,15 @@ use std::sync::{Arc, LazyLock};
 #[cfg(feature = "e164")]
 include!(concat!(env!("OUT_DIR"), "/country_codes.rs"));
 
+#[cfg(feature = "schemars")]
+mod schemars_feature;
+
+#[cfg(feature = "serde")]
+mod serde_feature;
+
+#[cfg(feature = "serde")]
+pub use serde_feature::deserialize_lenient;
+
 #[cfg(feature = "e164")]
 static E_164_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\+(\d{1,15})$").unwrap());
 
@@ -18,16 +27,74 @@ static WITH_COUNTRY_CODE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 static WITHOUT_COUNTRY_CODE_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^\(?(\d{2,4})\)?[\s\-\.]?(\d{2,4})[\s\-\.]?(\d{2,4})$").unwrap());
 
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "serde", serde(try_from = "String"))]
-#[cfg_attr(feature = "serde", serde(into = "String"))]
+#[derive(Debug, Clone)]
 pub struct Phone {
     country_code: Option<Arc<str>>,
     number: Arc<str>,
+    area_code: Option<Arc<str>>,
+    prefix: Arc<str>,
+    line_number: Arc<str>,
+}
+
+/// Equality only considers `country_code` and `number`: two `Phone`s that parsed to
+/// the same logical number are equal even if they were built through different
+/// constructors and so decomposed their components differently (e.g.
+/// `from_e_164` vs `build_with_country_code` may disagree on where the area code
+/// ends, despite describing the same number).
+impl PartialEq for Phone {
+    fn eq(&self, other: &Self) -> bool {
+        self.country_code == other.country_code && self.number == other.number
+    }
+}
+
+impl Eq for Phone {}
+
+/// The national number broken into the segments the parsing regexes capture:
+/// an optional area code, the exchange prefix, and the line number.
+///
+/// `area_code` is `None` when the input had no distinguishable first group: a bare
+/// 10-digit run with no separators at all (e.g. `"5551234567"`), where the regex's
+/// capture groups split the digits at essentially arbitrary points, and any number
+/// parsed with [`Phone::from_e_164`]. Inputs with real separators — spaces, dashes,
+/// dots, or parentheses — keep the groups as the separators delimit them (3/3/4 for
+/// `"555-123-4567"` or `"(555) 123-4567"`, 2/4/4 for `"(55) 1234-5678"`) and always
+/// have an area code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoneComponents {
+    country_code: Option<Arc<str>>,
+    area_code: Option<Arc<str>>,
+    prefix: Arc<str>,
+    line_number: Arc<str>,
+}
+
+impl PhoneComponents {
+    pub fn country_code(&self) -> Option<&str> {
+        self.country_code.as_deref()
+    }
+
+    pub fn area_code(&self) -> Option<&str> {
+        self.area_code.as_deref()
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn line_number(&self) -> &str {
+        &self.line_number
+    }
+}
+
+/// Selects the canonical representation returned by [`Phone::format`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PhoneFormat {
+    /// `+<country_code><number>`, with all separators stripped; just `<number>` when
+    /// no country code was detected.
+    E164,
+    /// The national number only, grouped with `-`.
+    National,
+    /// `+<country_code> ` followed by the grouped national number.
+    International,
 }
 
 impl Phone {
@@ -56,6 +123,9 @@ impl Phone {
         let phone = Phone {
             country_code,
             number,
+            area_code: Some(Arc::from(&captures[2])),
+            prefix: Arc::from(&captures[3]),
+            line_number: Arc::from(&captures[4]),
         };
 
         Ok(phone)
@@ -72,9 +142,31 @@ impl Phone {
             .collect();
         let number = Arc::from(number);
 
+        // A bare 10-digit run with no separators at all gives the regex nothing
+        // to anchor an area code on (its three capture groups split the digits
+        // at essentially arbitrary points), so fall back to splitting off the
+        // trailing line number and leave the area code unset rather than
+        // presenting a guess as fact. Inputs with real separators (spaces,
+        // dashes, dots, parens) already get a clean capture of the groups the
+        // separators delimit and should keep it.
+        let is_ambiguous_run = number.len() == 10 && input.chars().all(|c| c.is_ascii_digit());
+        let (area_code, prefix, line_number) = if is_ambiguous_run {
+            let (prefix, line_number) = split_trailing_line_number(&number);
+            (None, prefix, line_number)
+        } else {
+            (
+                Some(Arc::from(&captures[1])),
+                Arc::from(&captures[2]),
+                Arc::from(&captures[3]),
+            )
+        };
+
         let phone = Phone {
             country_code: None,
             number,
+            area_code,
+            prefix,
+            line_number,
         };
 
         Ok(phone)
@@ -88,56 +180,53 @@ impl Phone {
         &self.number
     }
 
+    /// Returns the national number split into its area code, prefix, and line
+    /// number components, as captured while parsing.
+    pub fn components(&self) -> PhoneComponents {
+        PhoneComponents {
+            country_code: self.country_code.clone(),
+            area_code: self.area_code.clone(),
+            prefix: self.prefix.clone(),
+            line_number: self.line_number.clone(),
+        }
+    }
+
     pub fn number_with_separator(&self, separator: char) -> String {
-        let number = &self.number;
-        let len = number.len();
+        group_digits_with_separator(&self.number, separator)
+    }
 
-        if len < 4 {
-            return number.to_string();
-        }
+    /// Returns the number in E.164 form (`+<country_code><number>`), or `None` when
+    /// no country code was detected while parsing.
+    pub fn to_e164(&self) -> Option<String> {
+        self.country_code
+            .as_ref()
+            .map(|code| format!("+{}{}", code, self.number))
+    }
 
-        // Format based on common patterns
-        match len {
-            10 => self.ten_digits_with_separator(separator),
-            11 => self.eleven_digits_with_separator(separator),
-            _ => {
-                // For other lengths, insert separator every 3 digits
-                let mut result = String::with_capacity(number.len() + (number.len() / 3));
-                for (i, c) in number.chars().enumerate() {
-                    if i > 0 && i % 3 == 0 {
-                        result.push(separator);
-                    }
-                    result.push(c);
-                }
-                result
-            }
-        }
+    /// Returns the national number grouped with `separator`, without a country code.
+    pub fn format_national(&self, separator: char) -> String {
+        self.number_with_separator(separator)
     }
 
-    // Format as XXX-XXX-XXXX
-    fn ten_digits_with_separator(&self, separator: char) -> String {
-        format!(
-            "{}{}{}{}{}",
-            &self.number[0..3],
-            separator,
-            &self.number[3..6],
-            separator,
-            &self.number[6..10]
-        )
+    /// Returns `+<country_code> ` followed by the grouped national number. Falls back
+    /// to [`Phone::format_national`] when no country code was detected.
+    pub fn format_international(&self, separator: char) -> String {
+        match &self.country_code {
+            Some(code) => format!("+{} {}", code, self.number_with_separator(separator)),
+            None => self.format_national(separator),
+        }
     }
 
-    // Format as X-XXX-XXX-XXXX
-    fn eleven_digits_with_separator(&self, separator: char) -> String {
-        format!(
-            "{}{}{}{}{}{}{}",
-            &self.number[0..1],
-            separator,
-            &self.number[1..4],
-            separator,
-            &self.number[4..7],
-            separator,
-            &self.number[7..11]
-        )
+    /// Dispatches to [`Phone::to_e164`], [`Phone::format_national`], or
+    /// [`Phone::format_international`] based on `fmt`, always grouping with `-`.
+    /// For [`PhoneFormat::E164`] with no detected country code, returns the bare
+    /// national number without a `+`.
+    pub fn format(&self, fmt: PhoneFormat) -> String {
+        match fmt {
+            PhoneFormat::E164 => self.to_e164().unwrap_or_else(|| self.number.to_string()),
+            PhoneFormat::National => self.format_national('-'),
+            PhoneFormat::International => self.format_international('-'),
+        }
     }
 
     #[cfg(feature = "e164")]
@@ -147,9 +236,13 @@ impl Phone {
         }
 
         if let Some((code, national_number)) = parse_e164(s) {
+            let (prefix, line_number) = split_trailing_line_number(national_number);
             let phone = Phone {
                 country_code: Some(Arc::from(code)),
                 number: Arc::from(national_number),
+                area_code: None,
+                prefix,
+                line_number,
             };
             return Ok(phone);
         }
@@ -163,6 +256,62 @@ impl Phone {
             .as_ref()
             .and_then(|code| find_country_info(code))
     }
+
+    /// Checks the national number's length against the lengths known to be valid for
+    /// the detected country.
+    ///
+    /// Returns `None` when validity can't be determined: either no country was
+    /// detected, or the country is known but `data/country_codes.json` has no
+    /// length data for it. Returns `Some(true)`/`Some(false)` only when we actually
+    /// have lengths to check against.
+    #[cfg(feature = "e164")]
+    pub fn is_valid(&self) -> Option<bool> {
+        let info = self.country_info()?;
+        if info.valid_lengths.is_empty() {
+            return None;
+        }
+
+        Some(info.valid_lengths.contains(&(self.number.len() as u8)))
+    }
+
+    /// Like [`Phone::from_e_164`], but also rejects numbers whose national-number
+    /// length is known to be invalid for the detected country. Numbers whose
+    /// validity can't be determined (see [`Phone::is_valid`]) are accepted.
+    #[cfg(feature = "e164")]
+    pub fn from_e_164_validated(s: &str) -> Result<Self, Error> {
+        let phone = Phone::from_e_164(s)?;
+        match phone.is_valid() {
+            Some(false) => Err(Error::InvalidNationalNumberLength),
+            _ => Ok(phone),
+        }
+    }
+
+    /// Parses `input`, defaulting to the dial code registered for `iso_code` when the
+    /// input itself doesn't carry a `+country_code`.
+    ///
+    /// `iso_code` is an ISO 3166-1 alpha-2 region code (e.g. `"MX"`).
+    #[cfg(feature = "e164")]
+    pub fn build_with_region(input: &str, iso_code: &str) -> Result<Self, Error> {
+        // `build_with_country_code`'s regex captures a country code whenever the
+        // input happens to start with enough digits in the right shape — its
+        // leading `+` is optional, and it never validates the
+        // capture against `COUNTRY_CODES`. Without gating on an explicit `+`,
+        // a bare national number like "81 1234 5678" would be misparsed as
+        // having country code "81", silently dropping digits and never
+        // consulting `iso_code` at all. Only trust it when the input actually
+        // opts into carrying its own country code.
+        if input.trim_start().starts_with('+') {
+            if let Ok(phone) = Phone::build_with_country_code(input) {
+                return Ok(phone);
+            }
+        }
+
+        let dial_code = find_dial_code_for_region(iso_code).ok_or(Error::UnknownRegion)?;
+        let mut phone = Phone::build_without_country_code(input)?;
+        phone.country_code = Some(Arc::from(dial_code));
+
+        Ok(phone)
+    }
 }
 
 impl Display for Phone {
@@ -175,6 +324,126 @@ impl Display for Phone {
     }
 }
 
+/// Splits a national number with no reliable area-code boundary into a prefix and a
+/// trailing 4-digit line number, used when the regex captures can't be trusted.
+fn split_trailing_line_number(number: &str) -> (Arc<str>, Arc<str>) {
+    if number.len() > 4 {
+        let split = number.len() - 4;
+        (Arc::from(&number[..split]), Arc::from(&number[split..]))
+    } else {
+        (Arc::from(""), Arc::from(number))
+    }
+}
+
+/// Groups a run of digits using the same patterns `Phone::number_with_separator` relies
+/// on: `XXX-XXX-XXXX` for 10 digits, `X-XXX-XXX-XXXX` for 11, and a separator every 3
+/// digits for anything else (including numbers still being typed).
+fn group_digits_with_separator(digits: &str, separator: char) -> String {
+    let len = digits.len();
+
+    if len < 4 {
+        return digits.to_string();
+    }
+
+    match len {
+        10 => format!(
+            "{}{}{}{}{}",
+            &digits[0..3],
+            separator,
+            &digits[3..6],
+            separator,
+            &digits[6..10]
+        ),
+        11 => format!(
+            "{}{}{}{}{}{}{}",
+            &digits[0..1],
+            separator,
+            &digits[1..4],
+            separator,
+            &digits[4..7],
+            separator,
+            &digits[7..11]
+        ),
+        _ => {
+            let mut result = String::with_capacity(digits.len() + (digits.len() / 3));
+            for (i, c) in digits.chars().enumerate() {
+                if i > 0 && i % 3 == 0 {
+                    result.push(separator);
+                }
+                result.push(c);
+            }
+            result
+        }
+    }
+}
+
+/// Formats a phone number progressively as the user enters each digit, without having
+/// to re-parse the accumulated string on every keystroke.
+///
+/// The formatter keeps the raw digits entered so far plus, once enough of them are
+/// available, the country code detected via [`find_country_code`]. A leading `+`
+/// opens a country-code context: subsequent digits are matched against
+/// [`ORDERED_COUNTRY_CODES`] as they come in, and once a country code is found it is
+/// no longer counted as part of the national number.
+#[cfg(feature = "e164")]
+#[derive(Debug, Default, Clone)]
+pub struct AsYouTypeFormatter {
+    digits: String,
+    leading_plus: bool,
+    country_code: Option<&'static str>,
+}
+
+#[cfg(feature = "e164")]
+impl AsYouTypeFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends one character and returns the best-effort formatted string so far.
+    ///
+    /// Non-digit characters are ignored, with the exception of a leading `+`, which
+    /// marks the buffer as being in a country-code context. Entered digits are never
+    /// reordered or dropped.
+    pub fn input_digit(&mut self, c: char) -> String {
+        if c == '+' {
+            if self.digits.is_empty() {
+                self.leading_plus = true;
+            }
+        } else if c.is_ascii_digit() {
+            self.digits.push(c);
+            if self.leading_plus && self.country_code.is_none() {
+                self.country_code = find_country_code(&self.digits);
+            }
+        }
+
+        self.format()
+    }
+
+    /// Resets the formatter to an empty buffer.
+    pub fn clear(&mut self) {
+        self.digits.clear();
+        self.leading_plus = false;
+        self.country_code = None;
+    }
+
+    fn national_digits(&self) -> &str {
+        match self.country_code {
+            Some(code) => &self.digits[code.len()..],
+            None => &self.digits,
+        }
+    }
+
+    fn format(&self) -> String {
+        let national = group_digits_with_separator(self.national_digits(), '-');
+
+        match self.country_code {
+            Some(code) => format!("+{} {}", code, national),
+            None if self.leading_plus => format!("+{}", national),
+            None => national,
+        }
+    }
+}
+
 impl FromStr for Phone {
     type Err = Error;
 
@@ -208,6 +477,14 @@ pub enum Error {
     #[cfg(feature = "e164")]
     #[error("Not E.164 format")]
     NotE164Format,
+
+    #[cfg(feature = "e164")]
+    #[error("Invalid national number length for detected country")]
+    InvalidNationalNumberLength,
+
+    #[cfg(feature = "e164")]
+    #[error("Unknown region")]
+    UnknownRegion,
 }
 
 #[cfg(test)]
@@ -234,6 +511,65 @@ mod tests {
         let deserialized: Phone = serde_json::from_str(&serialized).unwrap();
         assert_eq!(phone, deserialized);
     }
+
+    #[test]
+    fn to_e164_requires_country_code() {
+        let phone = Phone::build_with_country_code("+52 111 111 1111").unwrap();
+        assert_eq!(phone.to_e164().as_deref(), Some("+521111111111"));
+
+        let phone = Phone::build_without_country_code("111 111 1111").unwrap();
+        assert_eq!(phone.to_e164(), None);
+    }
+
+    #[test]
+    fn format_national_and_international() {
+        let phone = Phone::build_with_country_code("+52 111 111 1111").unwrap();
+        assert_eq!(phone.format_national('-'), "111-111-1111");
+        assert_eq!(phone.format_international('-'), "+52 111-111-1111");
+
+        let phone = Phone::build_without_country_code("111 111 1111").unwrap();
+        assert_eq!(phone.format_international('-'), phone.format_national('-'));
+    }
+
+    #[test]
+    fn format_dispatches_by_variant() {
+        let phone = Phone::build_with_country_code("+52 111 111 1111").unwrap();
+        assert_eq!(phone.format(PhoneFormat::E164), "+521111111111");
+        assert_eq!(phone.format(PhoneFormat::National), "111-111-1111");
+        assert_eq!(phone.format(PhoneFormat::International), "+52 111-111-1111");
+    }
+
+    #[test]
+    fn components_from_parenthesized_area_code() {
+        let phone = Phone::build_without_country_code("(55) 1234-5678").unwrap();
+        let components = phone.components();
+        assert_eq!(components.area_code(), Some("55"));
+        assert_eq!(components.prefix(), "1234");
+        assert_eq!(components.line_number(), "5678");
+    }
+
+    #[test]
+    fn components_fall_back_when_area_code_is_ambiguous() {
+        let phone = Phone::build_without_country_code("1234567890").unwrap();
+        let components = phone.components();
+        assert_eq!(components.area_code(), None);
+        assert_eq!(components.line_number(), "7890");
+    }
+
+    #[test]
+    fn components_keep_clean_split_for_separator_delimited_input() {
+        let phone = Phone::build_without_country_code("555 123 4567").unwrap();
+        let components = phone.components();
+        assert_eq!(components.area_code(), Some("555"));
+        assert_eq!(components.prefix(), "123");
+        assert_eq!(components.line_number(), "4567");
+
+        let phone = Phone::build_without_country_code("555-123-4567").unwrap();
+        let components = phone.components();
+        assert_eq!(components.area_code(), Some("555"));
+        assert_eq!(components.prefix(), "123");
+        assert_eq!(components.line_number(), "4567");
+    }
 }
 
 #[cfg(test)]
@@ -348,4 +684,91 @@ pub mod e_164_tests {
         let short = parse_e164("+123");
         assert!(short.is_none());
     }
+
+    #[test]
+    fn is_valid_is_unknown_without_a_detected_country() {
+        let phone = Phone::build_without_country_code("111 111 1111").unwrap();
+        assert_eq!(phone.is_valid(), None, "no country code detected, can't validate");
+    }
+
+    #[test]
+    fn from_e_164_validated_rejects_bad_format() {
+        assert!(matches!(
+            Phone::from_e_164_validated("invalid"),
+            Err(Error::NotE164Format)
+        ));
+    }
+
+    #[test]
+    fn build_with_region_defaults_the_country_code() {
+        let phone = Phone::build_with_region("(55) 1234-5678", "MX").unwrap();
+        assert_eq!(phone.country_code(), Some("52"));
+        assert_eq!(phone.number(), "5512345678");
+    }
+
+    #[test]
+    fn build_with_region_rejects_unknown_region() {
+        assert!(matches!(
+            Phone::build_with_region("(55) 1234-5678", "ZZ"),
+            Err(Error::UnknownRegion)
+        ));
+    }
+
+    #[test]
+    fn build_with_region_does_not_misparse_bare_national_numbers_as_country_codes() {
+        // No leading `+`, so this must go through the region-default path rather
+        // than being (mis)matched by `build_with_country_code`, which would
+        // otherwise treat "81" as a country code and drop it from the number.
+        let phone = Phone::build_with_region("81 1234 5678", "MX").unwrap();
+        assert_eq!(phone.country_code(), Some("52"));
+        assert_eq!(phone.number(), "8112345678");
+    }
+
+    #[test]
+    fn equality_ignores_components_and_only_compares_country_code_and_number() {
+        let from_e164 = Phone::from_e_164("+521234567890").unwrap();
+        let from_country_code = Phone::build_with_country_code("+52 1234 567 890").unwrap();
+
+        // Same country code and number, but the two constructors disagree on
+        // where the area code ends.
+        assert_ne!(from_e164.components(), from_country_code.components());
+        assert_eq!(from_e164, from_country_code);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "e164")]
+mod as_you_type_tests {
+    use super::*;
+
+    #[test]
+    fn formats_without_country_code() {
+        let mut formatter = AsYouTypeFormatter::new();
+        let mut last = String::new();
+        for c in "5551234567".chars() {
+            last = formatter.input_digit(c);
+        }
+        assert_eq!(last, "555-123-4567");
+    }
+
+    #[test]
+    fn detects_country_code_after_leading_plus() {
+        let mut formatter = AsYouTypeFormatter::new();
+        for c in "+521234".chars() {
+            formatter.input_digit(c);
+        }
+        assert_eq!(formatter.country_code, Some("52"));
+
+        let last = formatter.input_digit('5');
+        assert_eq!(last, "+52 123-45");
+    }
+
+    #[test]
+    fn clear_resets_the_buffer() {
+        let mut formatter = AsYouTypeFormatter::new();
+        formatter.input_digit('+');
+        formatter.input_digit('1');
+        formatter.clear();
+        assert_eq!(formatter.input_digit('5'), "5");
+    }
 }
@@ -0,0 +1,70 @@
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use schemars::JsonSchema;
+
+use crate::{Phone, WITHOUT_COUNTRY_CODE_REGEX, WITH_COUNTRY_CODE_REGEX};
+
+/// Rewrites the one Rust/PCRE-style named capture group the phone regexes use
+/// (`(?P<country_code>...)`) to a plain, unnamed group; other named groups are
+/// left as they are. JSON Schema's `pattern` must be a valid ECMA-262 regex, and
+/// `(?P<name>...)` isn't: most ECMA-262 engines (and the ajv/openapi-generator
+/// consumers this schema targets) reject it outright.
+fn to_json_schema_pattern(regex: &str) -> String {
+    regex.replace("(?P<country_code>", "(")
+}
+
+impl JsonSchema for Phone {
+    fn schema_name() -> String {
+        "Phone".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        let pattern = format!(
+            "({})|({})",
+            to_json_schema_pattern(WITH_COUNTRY_CODE_REGEX.as_str()),
+            to_json_schema_pattern(WITHOUT_COUNTRY_CODE_REGEX.as_str())
+        );
+
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(pattern),
+                ..Default::default()
+            })),
+            metadata: Some(Box::new(Metadata {
+                description: Some("A phone number, with or without a country code".to_string()),
+                examples: vec![serde_json::json!("+52-111-111-1111")],
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_describes_a_pattern_string() {
+        let mut gen = SchemaGenerator::default();
+        let schema = Phone::json_schema(&mut gen).into_object();
+        assert_eq!(schema.instance_type, Some(InstanceType::String.into()));
+        assert!(schema.string.unwrap().pattern.is_some());
+    }
+
+    #[test]
+    fn pattern_has_no_pcre_only_named_groups() {
+        let mut gen = SchemaGenerator::default();
+        let schema = Phone::json_schema(&mut gen).into_object();
+        let pattern = schema.string.unwrap().pattern.unwrap();
+
+        // `(?P<name>...)` is Rust/PCRE syntax and is rejected by ECMA-262 regex
+        // engines (e.g. `new RegExp` in JS, which most JSON Schema tooling uses
+        // to validate `pattern`). Also sanity-check the pattern still compiles
+        // as a regex at all.
+        assert!(!pattern.contains("(?P<"), "pattern must not use PCRE named groups: {pattern}");
+        assert!(regex::Regex::new(&pattern).is_ok());
+    }
+}
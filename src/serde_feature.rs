@@ -1,6 +1,6 @@
 use serde::{
     de::{Error, Unexpected, Visitor},
-    Serialize, Deserialize,
+    Deserialize, Deserializer, Serialize,
 };
 
 use crate::Phone;
@@ -10,34 +10,24 @@ impl Serialize for Phone {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(self.as_str())
+        serializer.serialize_str(&self.to_string())
     }
 }
 
 pub struct PhoneVisitor;
 
 impl<'de> Visitor<'de> for PhoneVisitor {
-    
     type Value = Phone;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a string whith a phone structure")
+        formatter.write_str("a string or integer with a phone number structure")
     }
 
     fn visit_str<E>(self, str: &str) -> Result<Self::Value, E>
-        where
-            E: Error,
+    where
+        E: Error,
     {
-        let phone_result = Phone::new_with_country(str)
-            .map_err(|_| Error::invalid_value(Unexpected::Str(str), &self));
-
-        if let Err(_e) = phone_result {
-            return Phone::new_with_country(str)
-                .map_err(|_| Error::invalid_value(Unexpected::Str(str), &self));
-        }
-
-        phone_result
-        
+        Phone::build(str).map_err(|_| Error::invalid_value(Unexpected::Str(str), &self))
     }
 
     fn visit_string<E>(self, str: String) -> Result<Self::Value, E>
@@ -47,23 +37,46 @@ impl<'de> Visitor<'de> for PhoneVisitor {
         self.visit_str(&str)
     }
 
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let digits = value.to_string();
+        Phone::build(&digits).map_err(|_| Error::invalid_value(Unexpected::Unsigned(value), &self))
+    }
 
-
-
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        let digits = value.to_string();
+        Phone::build(&digits).map_err(|_| Error::invalid_value(Unexpected::Signed(value), &self))
+    }
 }
 
 impl<'de> Deserialize<'de> for Phone {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
-        D: serde::Deserializer<'de>,
+        D: Deserializer<'de>,
     {
         deserializer.deserialize_str(PhoneVisitor)
     }
 }
 
+/// A lenient alternative to the default `Deserialize` impl, usable via
+/// `#[serde(deserialize_with = "deserialize_lenient")]`. Accepts a JSON string
+/// (`"+5215551234"`) as well as a bare JSON integer (`5215551234`), unlike the
+/// default `Deserialize` impl, which only accepts strings.
+pub fn deserialize_lenient<'de, D>(deserializer: D) -> Result<Phone, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(PhoneVisitor)
+}
+
 #[cfg(test)]
 mod tests {
-    use serde::{Serialize, Deserialize};
+    use serde::{Deserialize, Serialize};
     use serde_json::json;
 
     use crate::*;
@@ -78,7 +91,7 @@ mod tests {
     fn serialize_works() {
         let contact = Contact {
             name: "John Doe".to_string(),
-            phone: Phone::new("111 111 1111").unwrap(),
+            phone: Phone::build("111 111 1111").unwrap(),
         };
 
         let result = serde_json::to_string(&contact);
@@ -95,7 +108,7 @@ mod tests {
 
         let contact = Contact {
             name: "John Doe".to_string(),
-            phone: Phone::new("111 111 1111").unwrap(),
+            phone: Phone::build("111 111 1111").unwrap(),
         };
 
         let deserialize_result = serde_json::from_value::<Contact>(contact_json).unwrap();
@@ -104,36 +117,64 @@ mod tests {
         println!("{:?}", &deserialize_result);
     }
 
-     #[test]
+    #[test]
     fn deserialize_fails_correctly() {
         let bad_values = [
-        json!({
-            "name": "John Doe",
-            "phone": "+52 111 111 11"
-        }),
+            json!({
+                "name": "John Doe",
+                "phone": "+52 111 111 11"
+            }),
+            json!({
+                "name": "John Doe",
+                "phone": ""
+            }),
+            json!({
+                "name": "John Doe",
+                "phone": "text"
+            }),
+            json!({
+                "name": "John Doe",
+                "phone": ["111 111 11111"]
+            }),
+        ];
 
-        json!({
-            "name": "John Doe",
-            "phone": ""
-        }),
+        for value in bad_values {
+            serde_json::from_value::<Contact>(value).expect_err("deserialize must fail");
+        }
+    }
 
-        json!({
+    #[test]
+    fn deserialize_rejects_bare_numbers_by_default() {
+        let contact_json = json!({
             "name": "John Doe",
-            "phone": "text"
-        }),
+            "phone": 1111111111i64
+        });
+
+        serde_json::from_value::<Contact>(contact_json)
+            .expect_err("default Deserialize is string-only");
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct LenientContact {
+        pub name: String,
+        #[serde(deserialize_with = "deserialize_lenient")]
+        pub phone: Phone,
+    }
 
-        json!({
+    #[test]
+    fn deserialize_lenient_accepts_numbers_and_strings() {
+        let from_number = serde_json::from_value::<LenientContact>(json!({
             "name": "John Doe",
-            "phone": ["111 111 11111"]
-        }),
-        
-        ];
+            "phone": 1111111111i64
+        }))
+        .unwrap();
 
+        let from_string = serde_json::from_value::<LenientContact>(json!({
+            "name": "John Doe",
+            "phone": "111 111 1111"
+        }))
+        .unwrap();
 
-        for value in bad_values {
-            serde_json::from_value::<Contact>(value)
-                .expect_err("deserialize must fail");
-        }
+        assert_eq!(from_number.phone, from_string.phone);
     }
-
 }